@@ -0,0 +1,341 @@
+//! Affine transforms and map projections applied to generated geometries.
+//!
+//! The base [`AffineTransform`] lets generators translate/scale/rotate
+//! coordinates (e.g. to place a shape within a continent's bounding box).
+//! [`Projection`] lets a whole dataset be declared in a non-WGS84
+//! coordinate system once, rather than reprojecting downstream. It is a
+//! separate trait: projections do not use or compose with
+//! [`AffineTransform`].
+
+use geo::Coord;
+use geo::Geometry;
+
+/// A 2D affine transform `(x', y') = (a*x + b*y + xoff, d*x + e*y + yoff)`,
+/// matching the layout used by most GIS affine-transform conventions.
+#[derive(Debug, Clone, Copy)]
+pub struct AffineTransform {
+    pub a: f64,
+    pub b: f64,
+    pub xoff: f64,
+    pub d: f64,
+    pub e: f64,
+    pub yoff: f64,
+}
+
+impl AffineTransform {
+    /// The identity transform.
+    pub fn identity() -> Self {
+        AffineTransform {
+            a: 1.0,
+            b: 0.0,
+            xoff: 0.0,
+            d: 0.0,
+            e: 1.0,
+            yoff: 0.0,
+        }
+    }
+
+    /// A transform that translates by `(dx, dy)`.
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        AffineTransform {
+            a: 1.0,
+            b: 0.0,
+            xoff: dx,
+            d: 0.0,
+            e: 1.0,
+            yoff: dy,
+        }
+    }
+
+    /// A transform that scales by `(sx, sy)` about the origin.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        AffineTransform {
+            a: sx,
+            b: 0.0,
+            xoff: 0.0,
+            d: 0.0,
+            e: sy,
+            yoff: 0.0,
+        }
+    }
+
+    /// Applies this transform to a single coordinate.
+    pub fn apply(&self, coord: Coord<f64>) -> Coord<f64> {
+        Coord {
+            x: self.a * coord.x + self.b * coord.y + self.xoff,
+            y: self.d * coord.x + self.e * coord.y + self.yoff,
+        }
+    }
+
+    /// Composes `self` with `other`, applying `self` first then `other`.
+    pub fn then(&self, other: &AffineTransform) -> AffineTransform {
+        AffineTransform {
+            a: other.a * self.a + other.b * self.d,
+            b: other.a * self.b + other.b * self.e,
+            xoff: other.a * self.xoff + other.b * self.yoff + other.xoff,
+            d: other.d * self.a + other.e * self.d,
+            e: other.d * self.b + other.e * self.e,
+            yoff: other.d * self.xoff + other.e * self.yoff + other.yoff,
+        }
+    }
+}
+
+/// WGS84 semi-major axis (equatorial Earth radius) in meters, used as the
+/// sphere radius by the spherical Web Mercator projection below.
+const EARTH_RADIUS_M: f64 = 6_378_137.0;
+
+/// Web Mercator clamps latitude to keep the projection finite near the
+/// poles; this is the standard EPSG:3857 limit.
+const WEB_MERCATOR_MAX_LAT: f64 = 85.051_128_78;
+
+/// A coordinate reference system that can project WGS84 lon/lat to and
+/// from its own planar coordinates.
+///
+/// Implementations are expected to be lossy at the margins (e.g. Web
+/// Mercator's polar clamp); callers that need bit-exact round trips should
+/// stay in WGS84.
+pub trait Projection {
+    /// Projects a WGS84 `(lon, lat)` pair to this CRS's planar `(x, y)`.
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64);
+
+    /// Projects this CRS's planar `(x, y)` back to WGS84 `(lon, lat)`.
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64);
+
+    /// Whether this CRS is planar (no ±180° antimeridian to split on).
+    /// Spherical/ellipsoidal geographic CRSs should return `false`.
+    fn is_planar(&self) -> bool {
+        true
+    }
+
+    /// Returns a copy of `geometry` with every coordinate projected; the
+    /// input is left unchanged.
+    fn project_geometry(&self, geometry: &Geometry<f64>) -> Geometry<f64> {
+        use geo::MapCoordsInPlace;
+        let mut out = geometry.clone();
+        out.map_coords_in_place(|c| {
+            let (x, y) = self.forward(c.x, c.y);
+            Coord { x, y }
+        });
+        out
+    }
+}
+
+/// Spherical Web Mercator (EPSG:3857), as used by most web map tile sets.
+pub struct WebMercator;
+
+impl Projection for WebMercator {
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lat = lat.clamp(-WEB_MERCATOR_MAX_LAT, WEB_MERCATOR_MAX_LAT);
+        let lambda = lon.to_radians();
+        let phi = lat.to_radians();
+        let x = EARTH_RADIUS_M * lambda;
+        let y = EARTH_RADIUS_M * (std::f64::consts::FRAC_PI_4 + phi / 2.0).tan().ln();
+        (x, y)
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let lon = (x / EARTH_RADIUS_M).to_degrees();
+        let lat = (2.0 * (y / EARTH_RADIUS_M).exp().atan() - std::f64::consts::FRAC_PI_2)
+            .to_degrees();
+        (lon, lat)
+    }
+}
+
+/// Zone-based Universal Transverse Mercator, using the standard WGS84
+/// ellipsoid parameters and a fixed UTM zone/hemisphere.
+pub struct Utm {
+    pub zone: u8,
+    pub northern_hemisphere: bool,
+}
+
+impl Utm {
+    /// Builds a UTM projection for the given `zone` (1-60), in the northern
+    /// or southern hemisphere.
+    pub fn new(zone: u8, northern_hemisphere: bool) -> Self {
+        assert!((1..=60).contains(&zone), "UTM zone must be in 1..=60");
+        Utm {
+            zone,
+            northern_hemisphere,
+        }
+    }
+
+    /// Picks the UTM zone that contains `lon`.
+    pub fn zone_for_lon(lon: f64) -> u8 {
+        (((lon + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60) as u8
+    }
+
+    fn central_meridian(&self) -> f64 {
+        (self.zone as f64 - 1.0) * 6.0 - 180.0 + 3.0
+    }
+}
+
+// WGS84 ellipsoid constants and the standard UTM scale/false easting.
+const UTM_A: f64 = 6_378_137.0;
+const UTM_F: f64 = 1.0 / 298.257_223_563;
+const UTM_K0: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+const UTM_FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+
+impl Projection for Utm {
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let e_sq = UTM_F * (2.0 - UTM_F);
+        let phi = lat.to_radians();
+        let lambda = (lon - self.central_meridian()).to_radians();
+
+        let n = UTM_A / (1.0 - e_sq * phi.sin().powi(2)).sqrt();
+        let t = phi.tan().powi(2);
+        let c = e_sq / (1.0 - e_sq) * phi.cos().powi(2);
+        let a = lambda * phi.cos();
+
+        let m = UTM_A
+            * ((1.0 - e_sq / 4.0 - 3.0 * e_sq * e_sq / 64.0) * phi
+                - (3.0 * e_sq / 8.0 + 3.0 * e_sq * e_sq / 32.0) * (2.0 * phi).sin()
+                + (15.0 * e_sq * e_sq / 256.0) * (4.0 * phi).sin());
+
+        let x = UTM_K0
+            * n
+            * (a + (1.0 - t + c) * a.powi(3) / 6.0
+                + (5.0 - 18.0 * t + t * t) * a.powi(5) / 120.0)
+            + UTM_FALSE_EASTING;
+
+        let mut y = UTM_K0
+            * (m + n
+                * phi.tan()
+                * (a.powi(2) / 2.0
+                    + (5.0 - t + 9.0 * c + 4.0 * c * c) * a.powi(4) / 24.0
+                    + (61.0 - 58.0 * t + t * t) * a.powi(6) / 720.0));
+
+        if !self.northern_hemisphere {
+            y += UTM_FALSE_NORTHING_SOUTH;
+        }
+
+        (x, y)
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        // Closed-form footpoint-latitude inverse: the footpoint latitude
+        // comes from a truncated series in `mu`, with no iteration.
+        let e_sq = UTM_F * (2.0 - UTM_F);
+        let x = x - UTM_FALSE_EASTING;
+        let y = if self.northern_hemisphere {
+            y
+        } else {
+            y - UTM_FALSE_NORTHING_SOUTH
+        };
+
+        let m = y / UTM_K0;
+        let mu = m
+            / (UTM_A * (1.0 - e_sq / 4.0 - 3.0 * e_sq * e_sq / 64.0));
+
+        let e1 = (1.0 - (1.0 - e_sq).sqrt()) / (1.0 + (1.0 - e_sq).sqrt());
+        let phi1 = mu
+            + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+            + (21.0 * e1 * e1 / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+            + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin();
+
+        let n1 = UTM_A / (1.0 - e_sq * phi1.sin().powi(2)).sqrt();
+        let t1 = phi1.tan().powi(2);
+        let c1 = e_sq / (1.0 - e_sq) * phi1.cos().powi(2);
+        let r1 = UTM_A * (1.0 - e_sq) / (1.0 - e_sq * phi1.sin().powi(2)).powf(1.5);
+        let d = x / (n1 * UTM_K0);
+
+        let phi = phi1
+            - (n1 * phi1.tan() / r1)
+                * (d * d / 2.0
+                    - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1) * d.powi(4) / 24.0);
+        let lambda = (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0) / phi1.cos();
+
+        let lon = self.central_meridian() + lambda.to_degrees();
+        (lon, phi.to_degrees())
+    }
+
+    fn is_planar(&self) -> bool {
+        true
+    }
+}
+
+/// WGS84 is the generator's native, unprojected geographic CRS; the
+/// antimeridian splitter in [`super::antimeridian`] applies here since
+/// longitude wraps at ±180°.
+pub struct Wgs84;
+
+impl Projection for Wgs84 {
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (lon, lat)
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        (x, y)
+    }
+
+    fn is_planar(&self) -> bool {
+        false
+    }
+}
+
+/// Projects every coordinate of a whole geometry with `projection`. No
+/// antimeridian splitting is done here, whether or not `projection` is
+/// planar.
+pub fn project(geometry: &Geometry<f64>, projection: &dyn Projection) -> Geometry<f64> {
+    projection.project_geometry(geometry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affine_transform_then_composes_left_to_right() {
+        let translate = AffineTransform::translate(10.0, 0.0);
+        let scale = AffineTransform::scale(2.0, 2.0);
+        let composed = translate.then(&scale);
+
+        let direct = scale.apply(translate.apply(Coord { x: 1.0, y: 1.0 }));
+        assert_eq!(composed.apply(Coord { x: 1.0, y: 1.0 }), direct);
+    }
+
+    #[test]
+    fn web_mercator_round_trips() {
+        for (lon, lat) in [(0.0, 0.0), (-122.4194, 37.7749), (139.6917, 35.6895), (-179.9, -40.0)] {
+            let (x, y) = WebMercator.forward(lon, lat);
+            let (lon2, lat2) = WebMercator.inverse(x, y);
+            assert!((lon2 - lon).abs() < 1e-6, "lon {lon2} vs {lon}");
+            assert!((lat2 - lat).abs() < 1e-6, "lat {lat2} vs {lat}");
+        }
+        assert!(WebMercator.is_planar());
+    }
+
+    #[test]
+    fn web_mercator_clamps_latitude_near_poles() {
+        let (_, y_north) = WebMercator.forward(0.0, 89.9);
+        let (_, y_clamped) = WebMercator.forward(0.0, WEB_MERCATOR_MAX_LAT);
+        assert_eq!(y_north, y_clamped);
+    }
+
+    #[test]
+    fn utm_round_trips_within_a_meter() {
+        // A point well inside zone 33N (central meridian 15°E).
+        let utm = Utm::new(33, true);
+        let (lon, lat) = (15.3, 52.1);
+        let (x, y) = utm.forward(lon, lat);
+        let (lon2, lat2) = utm.inverse(x, y);
+
+        // ~1e-5 degrees of longitude/latitude is on the order of a meter.
+        assert!((lon2 - lon).abs() < 1e-5, "lon {lon2} vs {lon}");
+        assert!((lat2 - lat).abs() < 1e-5, "lat {lat2} vs {lat}");
+    }
+
+    #[test]
+    fn utm_zone_for_lon_picks_expected_zone() {
+        assert_eq!(Utm::zone_for_lon(15.3), 33);
+        assert_eq!(Utm::zone_for_lon(-122.4), 10);
+        assert_eq!(Utm::zone_for_lon(179.9), 60);
+        assert_eq!(Utm::zone_for_lon(-180.0), 1);
+    }
+
+    #[test]
+    fn wgs84_is_not_planar_and_is_identity() {
+        assert!(!Wgs84.is_planar());
+        assert_eq!(Wgs84.forward(12.3, 45.6), (12.3, 45.6));
+    }
+}
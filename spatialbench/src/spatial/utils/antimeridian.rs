@@ -0,0 +1,50 @@
+//! Splitting of lines that cross the ±180° antimeridian.
+//!
+//! Longitude wraps at ±180°, so a naive line segment from e.g. `179.5` to
+//! `-179.5` would otherwise appear to cross the entire globe instead of the
+//! short hop across the date line it actually represents. Splitting such
+//! segments into a `MultiLineString` keeps generated geometries valid for
+//! downstream spatial engines.
+
+use geo::{Geometry, LineString};
+
+/// Splits `line` into one or more pieces wherever it crosses the ±180°
+/// antimeridian, returning a single `LineString` unchanged if it never
+/// crosses, or a `MultiLineString` of the split pieces otherwise.
+pub fn split_at_antimeridian(line: &LineString<f64>) -> Geometry<f64> {
+    let coords = line.0.as_slice();
+    if coords.len() < 2 {
+        return Geometry::LineString(line.clone());
+    }
+
+    let mut pieces: Vec<LineString<f64>> = Vec::new();
+    let mut current: Vec<geo::Coord<f64>> = vec![coords[0]];
+
+    for window in coords.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        let delta = b.x - a.x;
+        if delta.abs() > 180.0 {
+            // Crossing the antimeridian: interpolate the latitude at the
+            // crossing point on both the ±180 edges and emit two segments.
+            let (a_wrapped, b_wrapped, sign) = if delta > 0.0 {
+                (a.x, b.x - 360.0, -1.0)
+            } else {
+                (a.x, b.x + 360.0, 1.0)
+            };
+            let t = (sign * 180.0 - a_wrapped) / (b_wrapped - a_wrapped);
+            let lat_cross = a.y + t * (b.y - a.y);
+
+            current.push(geo::coord! { x: sign * 180.0, y: lat_cross });
+            pieces.push(LineString::new(std::mem::take(&mut current)));
+            current.push(geo::coord! { x: -sign * 180.0, y: lat_cross });
+        }
+        current.push(b);
+    }
+    pieces.push(LineString::new(current));
+
+    if pieces.len() == 1 {
+        Geometry::LineString(pieces.into_iter().next().unwrap())
+    } else {
+        Geometry::MultiLineString(geo::MultiLineString::new(pieces))
+    }
+}
@@ -0,0 +1,524 @@
+//! Continent-relative sampling for generated geometries.
+
+use geo::{Geometry, LineString, Polygon};
+
+use super::affine::Projection;
+use super::antimeridian::split_at_antimeridian;
+use super::random::{BenchRng, WeightedSampler};
+
+/// A continent's sampling bounding box, expressed as WGS84 `(lon, lat)`
+/// corners. This is a coarse box rather than a true coastline, used only
+/// to bias where generated points fall.
+#[derive(Debug, Clone, Copy)]
+pub struct Continent {
+    pub name: &'static str,
+    pub min_lon: f64,
+    pub min_lat: f64,
+    pub max_lon: f64,
+    pub max_lat: f64,
+    /// Approximate land area in millions of km^2, used for area-weighted
+    /// sampling.
+    pub area_million_km2: f64,
+}
+
+/// The continents the generator draws points from, with approximate land
+/// areas (source: UN statistics, millions of km^2).
+pub const CONTINENTS: &[Continent] = &[
+    Continent { name: "Africa", min_lon: -17.6, min_lat: -34.8, max_lon: 51.4, max_lat: 37.3, area_million_km2: 30.4 },
+    Continent { name: "Asia", min_lon: 26.0, min_lat: -10.0, max_lon: 180.0, max_lat: 77.7, area_million_km2: 44.6 },
+    Continent { name: "Europe", min_lon: -24.5, min_lat: 34.8, max_lon: 60.0, max_lat: 71.2, area_million_km2: 10.2 },
+    Continent { name: "North America", min_lon: -168.0, min_lat: 5.5, max_lon: -52.0, max_lat: 83.1, area_million_km2: 24.7 },
+    Continent { name: "South America", min_lon: -81.3, min_lat: -55.9, max_lon: -34.8, max_lat: 12.5, area_million_km2: 17.8 },
+    Continent { name: "Oceania", min_lon: 112.9, min_lat: -47.3, max_lon: 180.0, max_lat: -0.5, area_million_km2: 8.5 },
+];
+
+/// Draws points uniformly within a continent's bounding box, with the
+/// continent itself chosen either uniformly or weighted by an arbitrary
+/// distribution (e.g. land area).
+pub struct ContinentSampler {
+    sampler: WeightedSampler,
+}
+
+impl ContinentSampler {
+    /// Selects continents uniformly at random.
+    pub fn uniform() -> Self {
+        let weights = vec![1.0; CONTINENTS.len()];
+        ContinentSampler {
+            sampler: WeightedSampler::new(&weights),
+        }
+    }
+
+    /// Selects continents in proportion to their approximate land area, so
+    /// larger continents receive proportionally more generated points.
+    pub fn area_weighted() -> Self {
+        let weights: Vec<f64> = CONTINENTS.iter().map(|c| c.area_million_km2).collect();
+        ContinentSampler {
+            sampler: WeightedSampler::new(&weights),
+        }
+    }
+
+    /// Selects continents according to a caller-supplied weight vector,
+    /// which must have one entry per [`CONTINENTS`] entry.
+    pub fn with_weights(weights: &[f64]) -> Self {
+        assert_eq!(weights.len(), CONTINENTS.len());
+        ContinentSampler {
+            sampler: WeightedSampler::new(weights),
+        }
+    }
+
+    /// Draws a uniform random `(lon, lat)` point within a continent chosen
+    /// according to this sampler's weighting.
+    pub fn sample_point(&self, rng: &mut BenchRng) -> (f64, f64) {
+        let continent = &CONTINENTS[self.sampler.sample(rng)];
+        let lon = continent.min_lon + rng.next_f64() * (continent.max_lon - continent.min_lon);
+        let lat = continent.min_lat + rng.next_f64() * (continent.max_lat - continent.min_lat);
+        (lon, lat)
+    }
+
+    /// Draws a point exactly as [`ContinentSampler::sample_point`] does,
+    /// then projects it into `projection`'s CRS so callers can declare an
+    /// output CRS once rather than reprojecting every generated point
+    /// downstream.
+    pub fn sample_point_in(&self, rng: &mut BenchRng, projection: &dyn Projection) -> (f64, f64) {
+        let (lon, lat) = self.sample_point(rng);
+        projection.forward(lon, lat)
+    }
+}
+
+/// A cell's state in the landmass wave-function-collapse grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Ocean,
+    Coast,
+    Land,
+}
+
+impl Tile {
+    /// All candidate states a cell may still collapse to.
+    fn all() -> [Tile; 3] {
+        [Tile::Ocean, Tile::Coast, Tile::Land]
+    }
+
+    /// Whether `self` is allowed to sit next to `other`: land may not
+    /// border ocean directly, it must have an intervening coast cell.
+    fn compatible_with(self, other: Tile) -> bool {
+        !matches!(
+            (self, other),
+            (Tile::Land, Tile::Ocean) | (Tile::Ocean, Tile::Land)
+        )
+    }
+}
+
+/// A coarse grid used to synthesize plausible landmass shapes via a
+/// wave-function-collapse-style constraint solver.
+struct LandmassGrid {
+    size: usize,
+    /// Remaining candidate tiles per cell; a singleton means collapsed.
+    domains: Vec<Vec<Tile>>,
+}
+
+impl LandmassGrid {
+    fn new(size: usize) -> Self {
+        LandmassGrid {
+            size,
+            domains: vec![Tile::all().to_vec(); size * size],
+        }
+    }
+
+    fn idx(&self, x: usize, y: usize) -> usize {
+        y * self.size + x
+    }
+
+    fn neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let mut out = Vec::with_capacity(4);
+        if x > 0 {
+            out.push((x - 1, y));
+        }
+        if x + 1 < self.size {
+            out.push((x + 1, y));
+        }
+        if y > 0 {
+            out.push((x, y - 1));
+        }
+        if y + 1 < self.size {
+            out.push((x, y + 1));
+        }
+        out
+    }
+
+    /// Collapses the cell at `(x, y)` to `tile` and propagates the
+    /// adjacency constraint to neighbors, pruning any tile no longer
+    /// compatible with a neighbor's remaining domain.
+    fn collapse(&mut self, x: usize, y: usize, tile: Tile) {
+        let i = self.idx(x, y);
+        self.domains[i] = vec![tile];
+        let mut queue: Vec<(usize, usize)> = self.neighbors(x, y);
+        while let Some((nx, ny)) = queue.pop() {
+            let i = self.idx(nx, ny);
+            let before = self.domains[i].len();
+            let neighbor_domains: Vec<Vec<Tile>> = self
+                .neighbors(nx, ny)
+                .iter()
+                .map(|&(ax, ay)| self.domains[self.idx(ax, ay)].clone())
+                .collect();
+            self.domains[i].retain(|&t| {
+                neighbor_domains
+                    .iter()
+                    .all(|domain| domain.iter().any(|&other| t.compatible_with(other)))
+            });
+            if self.domains[i].is_empty() {
+                // Over-constrained; fall back to coast, the tile compatible
+                // with everything.
+                self.domains[i] = vec![Tile::Coast];
+            }
+            if self.domains[i].len() < before {
+                queue.extend(self.neighbors(nx, ny));
+            }
+        }
+    }
+
+    /// Runs the lowest-entropy-first collapse loop until every cell has a
+    /// single remaining tile, biasing land cells towards `land_fraction` of
+    /// the grid.
+    fn run(&mut self, rng: &mut BenchRng, land_fraction: f64) {
+        loop {
+            let next = (0..self.size * self.size)
+                .filter(|&i| self.domains[i].len() > 1)
+                .min_by_key(|&i| self.domains[i].len());
+            let Some(i) = next else { break };
+            let (x, y) = (i % self.size, i / self.size);
+            let candidates = &self.domains[i];
+            let tile = if rng.next_f64() < land_fraction {
+                *candidates
+                    .iter()
+                    .find(|&&t| t == Tile::Land)
+                    .unwrap_or(&candidates[rng.next_index(candidates.len())])
+            } else {
+                candidates[rng.next_index(candidates.len())]
+            };
+            self.collapse(x, y, tile);
+        }
+    }
+
+    fn tile_at(&self, x: usize, y: usize) -> Tile {
+        self.domains[self.idx(x, y)][0]
+    }
+}
+
+/// Traces the boundary between "filled" (land or coast) and "ocean" cells
+/// of a collapsed [`LandmassGrid`], returning one closed ring (in grid
+/// *corner* coordinates, first point repeated as the last) per boundary
+/// loop: the outline of each connected filled region, plus a separate,
+/// clockwise ring around each ocean hole enclosed by a filled region.
+///
+/// This walks every unit-cell edge that separates a filled cell from a
+/// non-filled one (or the grid boundary), oriented so the filled region is
+/// on the left, then stitches those edges tip-to-tail into closed loops —
+/// the standard grid-contour ("marching squares" on a binary mask)
+/// technique. Unlike a per-component bounding box, this follows concave
+/// and L-shaped regions exactly and never claims ocean cells as land.
+///
+/// Two regions that touch only at a single corner (a diagonal pinch) both
+/// contribute an outgoing edge from that shared corner; both are kept
+/// rather than letting one silently overwrite the other, and the walk
+/// picks between them by always taking the sharpest left turn relative to
+/// how it arrived. That keeps each region's own loop intact through the
+/// pinch instead of entangling the two, and — since it only depends on
+/// local geometry, never on hash-map iteration order — gives the same
+/// rings every time for the same grid.
+fn trace_boundaries(grid: &LandmassGrid) -> Vec<Vec<(i64, i64)>> {
+    let filled = |x: i64, y: i64| {
+        x >= 0
+            && y >= 0
+            && (x as usize) < grid.size
+            && (y as usize) < grid.size
+            && grid.tile_at(x as usize, y as usize) != Tile::Ocean
+    };
+
+    // Collect every boundary edge, oriented counter-clockwise around each
+    // filled cell (bottom -> right -> top -> left), so the filled side is
+    // always on the edge's left. A BTreeMap (rather than a HashMap) keeps
+    // enumeration order below independent of the process's hash seed, and
+    // a Vec of outgoing edges per vertex (rather than a single slot) keeps
+    // both edges at a diagonal pinch instead of one clobbering the other.
+    let mut edges: std::collections::BTreeMap<(i64, i64), Vec<(i64, i64)>> =
+        std::collections::BTreeMap::new();
+    for gy in 0..grid.size as i64 {
+        for gx in 0..grid.size as i64 {
+            if !filled(gx, gy) {
+                continue;
+            }
+            let (x, y) = (gx, gy);
+            if !filled(x, y - 1) {
+                edges.entry((x, y)).or_default().push((x + 1, y));
+            }
+            if !filled(x + 1, y) {
+                edges.entry((x + 1, y)).or_default().push((x + 1, y + 1));
+            }
+            if !filled(x, y + 1) {
+                edges.entry((x + 1, y + 1)).or_default().push((x, y + 1));
+            }
+            if !filled(x - 1, y) {
+                edges.entry((x, y + 1)).or_default().push((x, y));
+            }
+        }
+    }
+
+    // Stitch edges into closed loops. At a vertex with more than one
+    // remaining outgoing edge, pick whichever turns the sharpest left
+    // relative to the direction we arrived from: the signed cross product
+    // of the incoming and outgoing direction vectors ranks left (+1) above
+    // straight (0) above right (-1).
+    let mut rings = Vec::new();
+    while let Some((&start, _)) = edges.iter().next() {
+        let mut ring = vec![start];
+        let mut current = start;
+        let mut incoming: Option<(i64, i64)> = None;
+        while edges.contains_key(&current) {
+            let pick = match incoming {
+                None => 0,
+                Some((dx, dy)) => edges[&current]
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|&(_, &(tx, ty))| dx * (ty - current.1) - dy * (tx - current.0))
+                    .map(|(i, _)| i)
+                    .unwrap_or(0),
+            };
+            let candidates = edges.get_mut(&current).unwrap();
+            let next = candidates.remove(pick);
+            if candidates.is_empty() {
+                edges.remove(&current);
+            }
+            incoming = Some((next.0 - current.0, next.1 - current.1));
+            ring.push(next);
+            if next == start {
+                break;
+            }
+            current = next;
+        }
+        if ring.len() > 2 {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// Synthesizes plausible irregular landmass polygons using a
+/// wave-function-collapse-style tile solver over a coarse `grid_size` ×
+/// `grid_size` grid (seeded deterministically from `seed`), so benchmarks
+/// aren't tied to real coastlines and can scale to arbitrary "worlds".
+///
+/// `land_fraction` biases roughly what share of collapsed cells end up as
+/// land (subject to the land/coast/ocean adjacency constraint).
+///
+/// When `projection` is `None` (or an unprojected geographic CRS), results
+/// are split at the ±180° antimeridian, so synthetic continents that wrap
+/// the globe are emitted as multiple polygons rather than one invalid one.
+/// When `projection` is a planar CRS (e.g. Web Mercator or UTM), the
+/// antimeridian split is bypassed — a planar CRS has no ±180°
+/// wraparound — and rings are projected directly.
+pub fn generate_landmasses(
+    seed: u64,
+    grid_size: usize,
+    land_fraction: f64,
+    projection: Option<&dyn Projection>,
+) -> Vec<Polygon<f64>> {
+    let mut rng = BenchRng::from_seed(seed);
+    let mut grid = LandmassGrid::new(grid_size);
+    grid.run(&mut rng, land_fraction);
+
+    let lon_step = 360.0 / grid_size as f64;
+    let lat_step = 180.0 / grid_size as f64;
+    let to_lon_lat = |gx: i64, gy: i64| {
+        (
+            -180.0 + gx as f64 * lon_step,
+            -90.0 + gy as f64 * lat_step,
+        )
+    };
+
+    trace_boundaries(&grid)
+        .into_iter()
+        .flat_map(|ring| {
+            let coords: Vec<geo::Coord<f64>> = ring
+                .iter()
+                .map(|&(gx, gy)| {
+                    let (lon, lat) = to_lon_lat(gx, gy);
+                    geo::coord! { x: lon, y: lat }
+                })
+                .collect();
+            match projection {
+                Some(p) if p.is_planar() => {
+                    let projected = p.project_geometry(&Geometry::LineString(LineString::new(coords)));
+                    match projected {
+                        Geometry::LineString(line) => vec![Polygon::new(line, vec![])],
+                        _ => unreachable!("projecting a LineString yields a LineString"),
+                    }
+                }
+                _ => split_ring_at_antimeridian(LineString::new(coords)),
+            }
+        })
+        .collect()
+}
+
+/// Splits a closed ring at the ±180° antimeridian, reusing the line
+/// splitter and closing each resulting piece along the meridian it was cut
+/// on. Returns the ring unchanged (as a single polygon) if it never
+/// crosses.
+fn split_ring_at_antimeridian(ring: LineString<f64>) -> Vec<Polygon<f64>> {
+    match split_at_antimeridian(&ring) {
+        Geometry::LineString(line) => vec![Polygon::new(line, vec![])],
+        Geometry::MultiLineString(pieces) => pieces
+            .into_iter()
+            .map(|mut piece| {
+                if piece.0.first() != piece.0.last() {
+                    let first = piece.0[0];
+                    piece.0.push(first);
+                }
+                Polygon::new(piece, vec![])
+            })
+            .collect(),
+        _ => unreachable!("split_at_antimeridian only returns (Multi)LineString"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::Area;
+
+    #[test]
+    fn traces_follow_the_actual_shape_not_a_bounding_box() {
+        // A 4x4 grid with an L-shaped filled region: three cells along the
+        // bottom row plus the two cells stacked above its left end. Its
+        // bounding box would be 3x3 cells (9 cells), but only 5 are actually
+        // filled, so a correct trace must have a smaller area than the bbox.
+        let mut grid = LandmassGrid::new(4);
+        for (x, y) in [(0, 0), (1, 0), (2, 0), (0, 1), (0, 2)] {
+            let i = grid.idx(x, y);
+            grid.domains[i] = vec![Tile::Land];
+        }
+        for y in 0..4 {
+            for x in 0..4 {
+                let i = grid.idx(x, y);
+                if grid.domains[i].len() != 1 {
+                    grid.domains[i] = vec![Tile::Ocean];
+                }
+            }
+        }
+
+        let rings = trace_boundaries(&grid);
+        assert_eq!(rings.len(), 1);
+        let ring = &rings[0];
+        assert_eq!(ring.first(), ring.last());
+
+        let polygon = Polygon::new(
+            LineString::new(
+                ring.iter()
+                    .map(|&(x, y)| geo::coord! { x: x as f64, y: y as f64 })
+                    .collect(),
+            ),
+            vec![],
+        );
+        // 5 unit cells -> area 5, vs. the 3x3=9 bounding box the old
+        // bbox-based tracer would have produced.
+        assert_eq!(polygon.unsigned_area(), 5.0);
+    }
+
+    #[test]
+    fn generate_landmasses_produces_closed_rings() {
+        let polygons = generate_landmasses(1234, 12, 0.4, None);
+        assert!(!polygons.is_empty());
+        for polygon in &polygons {
+            let ring = polygon.exterior();
+            assert_eq!(ring.0.first(), ring.0.last());
+            assert!(ring.0.len() >= 4);
+        }
+    }
+
+    #[test]
+    fn generate_landmasses_is_deterministic_for_a_given_seed() {
+        // Same seed/grid/land_fraction must always trace to the same
+        // rings: a corner shared by two diagonally-touching regions used
+        // to have an outgoing edge silently overwritten in a HashMap,
+        // making the result depend on hash-iteration order.
+        let a = generate_landmasses(1234, 12, 0.4, None);
+        let b = generate_landmasses(1234, 12, 0.4, None);
+        assert_eq!(a.len(), b.len());
+        for (pa, pb) in a.iter().zip(b.iter()) {
+            assert_eq!(pa.exterior().0, pb.exterior().0);
+        }
+    }
+
+    #[test]
+    fn diagonal_touch_traces_as_two_separate_rings() {
+        // Two land cells touching only at a shared corner: (0,0) and
+        // (1,1), with (1,0) and (0,1) left as ocean. A correct trace
+        // produces two disjoint 4-vertex squares meeting at (1,1); the
+        // old implementation entangled them into one malformed, unclosed
+        // ring because both cells tried to claim the same outgoing edge
+        // at that shared corner.
+        let mut grid = LandmassGrid::new(3);
+        for y in 0..3 {
+            for x in 0..3 {
+                let i = grid.idx(x, y);
+                grid.domains[i] = vec![Tile::Ocean];
+            }
+        }
+        for (x, y) in [(0, 0), (1, 1)] {
+            let i = grid.idx(x, y);
+            grid.domains[i] = vec![Tile::Land];
+        }
+
+        let rings = trace_boundaries(&grid);
+        assert_eq!(rings.len(), 2);
+        for ring in &rings {
+            assert_eq!(ring.first(), ring.last());
+            assert_eq!(ring.len(), 5);
+        }
+    }
+
+    #[test]
+    fn generate_landmasses_with_planar_projection_emits_projected_coords() {
+        use super::super::affine::WebMercator;
+
+        let polygons = generate_landmasses(1234, 12, 0.4, Some(&WebMercator));
+        assert!(!polygons.is_empty());
+        for polygon in &polygons {
+            // Web Mercator coordinates are in meters, far outside the
+            // [-180, 180] / [-90, 90] WGS84 range.
+            assert!(polygon.exterior().0.iter().any(|c| c.x.abs() > 1000.0));
+        }
+    }
+
+    #[test]
+    fn continent_sampler_area_weighted_favors_larger_continents() {
+        let sampler = ContinentSampler::area_weighted();
+        let mut rng = BenchRng::from_seed(99);
+        let mut asia_count = 0;
+        let asia_index = CONTINENTS.iter().position(|c| c.name == "Asia").unwrap();
+        for _ in 0..2000 {
+            let (lon, lat) = sampler.sample_point(&mut rng);
+            let c = &CONTINENTS[asia_index];
+            if lon >= c.min_lon && lon <= c.max_lon && lat >= c.min_lat && lat <= c.max_lat {
+                asia_count += 1;
+            }
+        }
+        // Asia has by far the largest area share; area-weighted sampling
+        // should draw noticeably more than a uniform 1/6 share would.
+        assert!(asia_count > 2000 / CONTINENTS.len());
+    }
+
+    #[test]
+    fn sample_point_in_matches_manually_projected_point() {
+        use super::super::affine::WebMercator;
+
+        let sampler = ContinentSampler::uniform();
+        let mut rng_a = BenchRng::from_seed(3);
+        let mut rng_b = BenchRng::from_seed(3);
+
+        let (lon, lat) = sampler.sample_point(&mut rng_a);
+        let (x, y) = sampler.sample_point_in(&mut rng_b, &WebMercator);
+
+        assert_eq!(WebMercator.forward(lon, lat), (x, y));
+    }
+}
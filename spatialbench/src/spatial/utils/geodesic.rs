@@ -0,0 +1,170 @@
+//! Geodesic trajectory generation (great-circle paths and bearings).
+//!
+//! Generating moving-object datasets as independent random points misses an
+//! entire class of benchmark queries (spatial-temporal range scans, KNN
+//! along a trajectory, trip reconstruction). This module generates
+//! connected polylines by walking along the sphere in a sequence of
+//! great-circle hops.
+
+use geo::{Geometry, LineString};
+
+use super::affine::Projection;
+use super::antimeridian::split_at_antimeridian;
+use super::random::BenchRng;
+
+/// Mean Earth radius in kilometers, used for the spherical approximations
+/// below.
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Great-circle distance between two WGS84 points, in kilometers, via the
+/// haversine formula.
+pub fn haversine_distance_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lon1, lat2, lon2) = (
+        lat1.to_radians(),
+        lon1.to_radians(),
+        lat2.to_radians(),
+        lon2.to_radians(),
+    );
+    let d_lat = lat2 - lat1;
+    let d_lon = lon2 - lon1;
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    let c = 2.0 * a.sqrt().asin();
+    EARTH_RADIUS_KM * c
+}
+
+/// Initial bearing (radians, clockwise from north) of the great-circle path
+/// from `(lat1, lon1)` to `(lat2, lon2)`.
+pub fn initial_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lon1, lat2, lon2) = (
+        lat1.to_radians(),
+        lon1.to_radians(),
+        lat2.to_radians(),
+        lon2.to_radians(),
+    );
+    let d_lon = lon2 - lon1;
+    let y = d_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+    y.atan2(x)
+}
+
+/// Computes the destination point reached by travelling angular distance
+/// `delta` (i.e. `distance_km / EARTH_RADIUS_KM`) from `(lat1, lon1)` along
+/// initial bearing `theta` (radians), on a spherical earth.
+pub fn destination_point(lat1: f64, lon1: f64, theta: f64, delta: f64) -> (f64, f64) {
+    let lat1 = lat1.to_radians();
+    let lon1 = lon1.to_radians();
+
+    let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * theta.cos()).asin();
+    let lon2 = lon1
+        + (theta.sin() * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * lat2.sin());
+
+    (lat2.to_degrees(), normalize_lon(lon2.to_degrees()))
+}
+
+/// Wraps a longitude into `(-180, 180]`.
+fn normalize_lon(lon: f64) -> f64 {
+    let wrapped = (lon + 180.0).rem_euclid(360.0) - 180.0;
+    if wrapped == -180.0 {
+        180.0
+    } else {
+        wrapped
+    }
+}
+
+/// Generates a random-walk trajectory of `n_steps` great-circle hops of
+/// `step_km` each, starting at `start` (`lat, lon`). Each step's heading is
+/// the previous heading perturbed by a uniform random jitter in
+/// `[-heading_jitter, heading_jitter]` radians (the first heading is drawn
+/// uniformly over a full circle).
+///
+/// When `projection` is `None` (or an unprojected geographic CRS), the
+/// path is split at the ±180° antimeridian via [`split_at_antimeridian`],
+/// so the result is a `LineString` or, if the walk crosses the date line,
+/// a `MultiLineString`. When `projection` is a planar CRS (e.g. Web
+/// Mercator or UTM), the antimeridian split is bypassed — a planar CRS has
+/// no ±180° wraparound — and the raw WGS84 path is projected directly.
+pub fn random_walk(
+    start: (f64, f64),
+    n_steps: usize,
+    step_km: f64,
+    heading_jitter: f64,
+    rng: &mut BenchRng,
+    projection: Option<&dyn Projection>,
+) -> Geometry<f64> {
+    let delta = step_km / EARTH_RADIUS_KM;
+    let mut heading = rng.next_f64() * std::f64::consts::TAU;
+
+    let (mut lat, mut lon) = start;
+    let mut points = vec![geo::coord! { x: lon, y: lat }];
+
+    for _ in 0..n_steps {
+        heading += (rng.next_f64() * 2.0 - 1.0) * heading_jitter;
+        let (next_lat, next_lon) = destination_point(lat, lon, heading, delta);
+        points.push(geo::coord! { x: next_lon, y: next_lat });
+        lat = next_lat;
+        lon = next_lon;
+    }
+
+    match projection {
+        Some(p) if p.is_planar() => p.project_geometry(&Geometry::LineString(LineString::new(points))),
+        _ => split_at_antimeridian(&LineString::new(points)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn haversine_distance_matches_known_reference() {
+        // London to Paris is ~344 km.
+        let km = haversine_distance_km(51.5074, -0.1278, 48.8566, 2.3522);
+        assert!((km - 344.0).abs() < 5.0, "got {km} km");
+    }
+
+    #[test]
+    fn haversine_distance_to_self_is_zero() {
+        assert_eq!(haversine_distance_km(10.0, 20.0, 10.0, 20.0), 0.0);
+    }
+
+    #[test]
+    fn destination_point_round_trips_with_bearing_and_distance() {
+        let (lat1, lon1) = (40.0, -73.0);
+        let theta = 1.0_f64; // radians
+        let delta = 500.0 / EARTH_RADIUS_KM;
+        let (lat2, lon2) = destination_point(lat1, lon1, theta, delta);
+
+        let distance = haversine_distance_km(lat1, lon1, lat2, lon2);
+        assert!((distance - 500.0).abs() < 1.0, "distance was {distance} km");
+
+        let bearing = initial_bearing(lat1, lon1, lat2, lon2);
+        assert!((bearing - theta).abs() < 0.01, "bearing was {bearing}");
+    }
+
+    #[test]
+    fn random_walk_produces_connected_n_plus_one_vertices() {
+        let mut rng = BenchRng::from_seed(5);
+        let geometry = random_walk((10.0, 10.0), 20, 50.0, 0.2, &mut rng, None);
+        match geometry {
+            Geometry::LineString(line) => assert_eq!(line.0.len(), 21),
+            Geometry::MultiLineString(pieces) => {
+                let total: usize = pieces.iter().map(|l| l.0.len()).sum();
+                // Each antimeridian crossing inserts two extra vertices (one
+                // on each ±180° edge) on top of the original n + 1 points.
+                assert!(total >= 21);
+            }
+            other => panic!("unexpected geometry variant: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn random_walk_with_planar_projection_bypasses_antimeridian_split() {
+        use super::super::affine::WebMercator;
+
+        let mut rng = BenchRng::from_seed(5);
+        let geometry = random_walk((10.0, 179.9), 20, 50.0, 0.2, &mut rng, Some(&WebMercator));
+        // A planar projection is never split into multiple pieces, even if
+        // the raw WGS84 path would have crossed the antimeridian.
+        assert!(matches!(geometry, Geometry::LineString(_)));
+    }
+}
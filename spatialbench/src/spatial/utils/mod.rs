@@ -1,9 +1,15 @@
 pub mod affine;
 mod antimeridian;
 pub mod continent;
+pub mod geodesic;
+pub mod hilbert;
+pub mod olc;
 pub mod random;
 
 pub use affine::*;
 pub use antimeridian::*;
 pub use continent::*;
+pub use geodesic::*;
+pub use hilbert::*;
+pub use olc::*;
 pub use random::*;
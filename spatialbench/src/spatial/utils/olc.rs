@@ -0,0 +1,150 @@
+//! Open Location Code ("Plus Code") encoding for generated points.
+//!
+//! Plus Codes give the benchmark a compact, human-readable geocode column
+//! derived purely from a point's coordinates, useful for exercising
+//! join/filter workloads that key off of a textual location identifier
+//! rather than lon/lat directly.
+
+/// Alphabet used for each OLC digit, ordered by value.
+const ALPHABET: &[u8] = b"23456789CFGHJMPQRVWX";
+const BASE: f64 = 20.0;
+const SEPARATOR: char = '+';
+const SEPARATOR_POSITION: usize = 8;
+const PAIR_CODE_LENGTH: usize = 10;
+const GRID_COLUMNS: u32 = 4;
+const GRID_ROWS: u32 = 5;
+const LAT_MAX: f64 = 90.0;
+const LON_MAX: f64 = 180.0;
+
+fn digit_value(c: char) -> Option<u32> {
+    ALPHABET
+        .iter()
+        .position(|&b| b == c.to_ascii_uppercase() as u8)
+        .map(|p| p as u32)
+}
+
+/// Encodes a WGS84 `(lon, lat)` pair into an Open Location Code of the
+/// requested `code_length` (clamped to at least 10, the full pair-code
+/// length).
+pub fn encode(lon: f64, lat: f64, code_length: usize) -> String {
+    let code_length = code_length.max(PAIR_CODE_LENGTH);
+
+    // Clip latitude to [-90, 90) and normalize longitude to [-180, 180),
+    // then shift both into non-negative ranges.
+    let lat = lat.clamp(-LAT_MAX, LAT_MAX - f64::EPSILON * LAT_MAX);
+    let lon = ((lon - (-LON_MAX)).rem_euclid(2.0 * LON_MAX)) + (-LON_MAX);
+    let mut lat = lat + LAT_MAX;
+    let mut lon = lon + LON_MAX;
+
+    let mut code = String::with_capacity(code_length + 1);
+    let mut lat_res = LAT_MAX * 2.0;
+    let mut lon_res = LON_MAX * 2.0;
+
+    // First five pairs: divide the remaining lat/lon range into 20
+    // subdivisions per axis and emit (lat digit, lon digit) per pair.
+    for pair in 0..5 {
+        lat_res /= BASE;
+        lon_res /= BASE;
+        let lat_digit = (lat / lat_res).floor() as u32;
+        let lon_digit = (lon / lon_res).floor() as u32;
+        lat -= lat_digit as f64 * lat_res;
+        lon -= lon_digit as f64 * lon_res;
+        code.push(ALPHABET[lat_digit as usize] as char);
+        code.push(ALPHABET[lon_digit as usize] as char);
+        if (pair + 1) * 2 == SEPARATOR_POSITION {
+            code.push(SEPARATOR);
+        }
+    }
+
+    // Beyond 10 digits, switch to 4x5 grid refinement.
+    if code_length > PAIR_CODE_LENGTH {
+        let mut lat_grid_res = lat_res;
+        let mut lon_grid_res = lon_res;
+        for _ in 0..(code_length - PAIR_CODE_LENGTH) {
+            lat_grid_res /= GRID_ROWS as f64;
+            lon_grid_res /= GRID_COLUMNS as f64;
+            let row = (lat / lat_grid_res).floor() as u32;
+            let col = (lon / lon_grid_res).floor() as u32;
+            lat -= row as f64 * lat_grid_res;
+            lon -= col as f64 * lon_grid_res;
+            let index = row * GRID_COLUMNS + col;
+            code.push(ALPHABET[index as usize] as char);
+        }
+    }
+
+    code
+}
+
+/// Decodes an Open Location Code produced by [`encode`] back into the
+/// `(lon, lat)` coordinate of the center of its enclosing cell.
+///
+/// Returns `None` if `code` is not a well-formed Plus Code.
+pub fn decode(code: &str) -> Option<(f64, f64)> {
+    let clean: String = code.chars().filter(|&c| c != SEPARATOR).collect();
+    if clean.len() < PAIR_CODE_LENGTH {
+        return None;
+    }
+
+    let mut lat = -LAT_MAX;
+    let mut lon = -LON_MAX;
+    let mut lat_res = LAT_MAX * 2.0;
+    let mut lon_res = LON_MAX * 2.0;
+    let chars: Vec<char> = clean.chars().collect();
+
+    for pair in 0..5 {
+        lat_res /= BASE;
+        lon_res /= BASE;
+        let lat_digit = digit_value(chars[pair * 2])?;
+        let lon_digit = digit_value(chars[pair * 2 + 1])?;
+        lat += lat_digit as f64 * lat_res;
+        lon += lon_digit as f64 * lon_res;
+    }
+
+    let mut lat_grid_res = lat_res;
+    let mut lon_grid_res = lon_res;
+    for &c in chars.iter().skip(PAIR_CODE_LENGTH) {
+        let index = digit_value(c)?;
+        lat_grid_res /= GRID_ROWS as f64;
+        lon_grid_res /= GRID_COLUMNS as f64;
+        let row = index / GRID_COLUMNS;
+        let col = index % GRID_COLUMNS;
+        lat += row as f64 * lat_grid_res;
+        lon += col as f64 * lon_grid_res;
+    }
+
+    // Return the center of the resulting cell.
+    Some((lon + lon_grid_res / 2.0, lat + lat_grid_res / 2.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_inserts_separator_and_uses_requested_length() {
+        let code = encode(-122.084, 37.4219, 11);
+        assert_eq!(code.len(), 12); // 11 digits + 1 separator
+        assert_eq!(code.chars().nth(8), Some('+'));
+        assert!(code.chars().filter(|c| *c != '+').all(|c| ALPHABET.contains(&(c as u8))));
+    }
+
+    #[test]
+    fn decode_recovers_point_within_cell_resolution() {
+        let cases = [(-122.084, 37.4219), (0.0, 0.0), (179.9, -89.9), (-179.9, 89.9)];
+        for (lon, lat) in cases {
+            let code = encode(lon, lat, 12);
+            let (decoded_lon, decoded_lat) = decode(&code).expect("valid code decodes");
+            // A 12-digit code resolves to roughly a 1m x 1m cell, so the
+            // decoded center should be very close to the original point.
+            assert!((decoded_lon - lon).abs() < 0.001, "lon mismatch for {code}");
+            assert!((decoded_lat - lat).abs() < 0.001, "lat mismatch for {code}");
+        }
+    }
+
+    #[test]
+    fn decode_rejects_malformed_codes() {
+        assert_eq!(decode(""), None);
+        assert_eq!(decode("23"), None);
+        assert_eq!(decode("23456789+!A"), None);
+    }
+}
@@ -0,0 +1,147 @@
+//! Shared random-number utilities for the generators in [`crate::spatial`].
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Deterministic RNG used throughout the benchmark's generators so that
+/// datasets are reproducible given the same seed.
+pub struct BenchRng {
+    inner: StdRng,
+}
+
+impl BenchRng {
+    /// Creates a new RNG seeded deterministically from `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        BenchRng {
+            inner: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Draws a uniform `f64` in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        self.inner.gen::<f64>()
+    }
+
+    /// Draws a uniform `usize` in `[0, bound)`.
+    pub fn next_index(&mut self, bound: usize) -> usize {
+        self.inner.gen_range(0..bound)
+    }
+}
+
+/// A reusable implementation of Vose's alias method for O(1) weighted
+/// sampling from a fixed discrete distribution.
+///
+/// Building the sampler is `O(n)`; each draw thereafter is `O(1)` rather
+/// than the `O(log n)` of a cumulative-weight binary search, which matters
+/// when sampling millions of points (e.g. picking a continent or a cell on
+/// a weighted grid) during generation.
+pub struct WeightedSampler {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl WeightedSampler {
+    /// Builds a sampler from non-negative `weights`. Panics if `weights` is
+    /// empty or all weights are zero.
+    pub fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        assert!(n > 0, "WeightedSampler requires at least one weight");
+        let sum: f64 = weights.iter().sum();
+        assert!(sum > 0.0, "WeightedSampler requires a positive weight sum");
+
+        let scaled: Vec<f64> = weights.iter().map(|w| w * n as f64 / sum).collect();
+        let mut prob = vec![0.0; n];
+        let mut alias = vec![0usize; n];
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        let mut scaled = scaled;
+        for (i, &p) in scaled.iter().enumerate() {
+            if p < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+        // Leftover entries are the result of floating-point rounding; they
+        // are effectively certain outcomes.
+        for i in large {
+            prob[i] = 1.0;
+        }
+        for i in small {
+            prob[i] = 1.0;
+        }
+
+        WeightedSampler { prob, alias }
+    }
+
+    /// Draws an index in proportion to the weights the sampler was built
+    /// with, in `O(1)` time.
+    pub fn sample(&self, rng: &mut BenchRng) -> usize {
+        let n = self.prob.len();
+        let i = rng.next_index(n);
+        let u = rng.next_f64();
+        if u < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_converges_to_the_given_weights() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        let sampler = WeightedSampler::new(&weights);
+        let mut rng = BenchRng::from_seed(7);
+        let mut counts = [0u32; 4];
+        let trials = 100_000;
+        for _ in 0..trials {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+
+        let total: f64 = weights.iter().sum();
+        for (i, &w) in weights.iter().enumerate() {
+            let expected = trials as f64 * w / total;
+            let observed = counts[i] as f64;
+            assert!(
+                (observed - expected).abs() / expected < 0.05,
+                "weight {i}: expected ~{expected}, got {observed}"
+            );
+        }
+    }
+
+    #[test]
+    fn sample_always_returns_a_valid_index() {
+        let weights = [0.001, 1000.0, 5.0];
+        let sampler = WeightedSampler::new(&weights);
+        let mut rng = BenchRng::from_seed(1);
+        for _ in 0..10_000 {
+            let i = sampler.sample(&mut rng);
+            assert!(i < weights.len());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_panics_on_empty_weights() {
+        WeightedSampler::new(&[]);
+    }
+}